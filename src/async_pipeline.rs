@@ -0,0 +1,198 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::stream::{FuturesUnordered, StreamExt};
+
+/// Async equivalent of `Pipeline`. Each item is pulled from the
+/// `AsyncSource` in sequence, but once it survives the filters it is
+/// saved to every sink concurrently rather than one sink at a time.
+pub struct AsyncPipeline<T> {
+    source: Box<dyn crate::source::AsyncSource<T> + Send>,
+    sinks: Vec<Arc<dyn crate::sink::AsyncSink<T> + Send + Sync>>,
+    filters: Vec<Box<dyn crate::filter::AsyncFilter<T> + Send + Sync>>,
+}
+
+pub struct AsyncPipelineBuilder<T> {
+    source: Option<Box<dyn crate::source::AsyncSource<T> + Send>>,
+    sinks: Vec<Arc<dyn crate::sink::AsyncSink<T> + Send + Sync>>,
+    filters: Vec<Box<dyn crate::filter::AsyncFilter<T> + Send + Sync>>,
+}
+
+impl<T> AsyncPipelineBuilder<T> {
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            sinks: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    pub fn add_source(mut self, source: Box<dyn crate::source::AsyncSource<T> + Send>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn add_sink(mut self, sink: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>) -> Self {
+        self.sinks.push(Arc::from(sink));
+        self
+    }
+
+    pub fn add_filter(
+        mut self,
+        filter: Box<dyn crate::filter::AsyncFilter<T> + Send + Sync>,
+    ) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// The async flavour of `PipelineBuilder::map`. Must be called
+    /// before any `add_sink`, for the same reason: sinks are typed for
+    /// `T` and cannot be carried forward once the builder becomes an
+    /// `AsyncPipelineBuilder<Out>`. Panics if any were already
+    /// registered, rather than silently dropping them.
+    ///
+    /// See `PipelineBuilder::map` for why this panics instead of
+    /// returning `BuilderError` like `build()` does.
+    pub fn map<Out: 'static>(
+        self,
+        transform: Box<dyn crate::transform::AsyncTransform<T, Out> + Send + Sync>,
+    ) -> AsyncPipelineBuilder<Out>
+    where
+        T: Send + 'static,
+    {
+        assert!(
+            self.sinks.is_empty(),
+            "map() must be called before add_sink(); sinks already registered for T would be silently dropped"
+        );
+
+        let source = self
+            .source
+            .expect("map() requires add_source() to be called first");
+
+        let wrapped = crate::transform::AsyncTransformedSource {
+            inner: source,
+            filters: self.filters,
+            transform,
+        };
+
+        AsyncPipelineBuilder {
+            source: Some(Box::new(wrapped)),
+            sinks: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    pub fn build(self) -> Result<AsyncPipeline<T>, crate::pipeline::BuilderError> {
+        let source = match self.source {
+            Some(source) => source,
+            None => return Err(crate::pipeline::BuilderError::NoSource),
+        };
+
+        Ok(AsyncPipeline {
+            source,
+            sinks: self.sinks,
+            filters: self.filters,
+        })
+    }
+}
+
+type SaveFuture = Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>> + Send>>;
+
+impl<T: Clone + Send + Sync + 'static> AsyncPipeline<T> {
+    /// Runs the pipeline to completion, keeping at most `concurrency`
+    /// `AsyncSink::save` calls in flight at once across all items and
+    /// sinks combined. Items are still pulled from the source in order,
+    /// but a slow sink for one item does not block saves for the next.
+    ///
+    /// Returns the first save error encountered; the remaining in-flight
+    /// saves are dropped (and so cancelled) rather than awaited further.
+    pub async fn run(mut self, concurrency: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let mut in_flight: FuturesUnordered<SaveFuture> = FuturesUnordered::new();
+
+        while let Some(item) = self.source.next().await {
+            let mut keep = true;
+            for filter in &self.filters {
+                if !filter.filter(&item).await {
+                    keep = false;
+                    break;
+                }
+            }
+            if !keep {
+                continue;
+            }
+
+            for sink in &self.sinks {
+                if in_flight.len() >= concurrency {
+                    if let Some(result) = in_flight.next().await {
+                        result?;
+                    }
+                }
+
+                let sink = sink.clone();
+                let item = item.clone();
+                in_flight.push(Box::pin(async move { sink.save(&item).await }));
+            }
+        }
+
+        while let Some(result) = in_flight.next().await {
+            result?;
+        }
+
+        for sink in &self.sinks {
+            sink.flush().await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::AsyncSink;
+    use crate::source::AsyncSource;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct VecSource {
+        items: Vec<i32>,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncSource<i32> for VecSource {
+        async fn next(&mut self) -> Option<i32> {
+            self.items.pop()
+        }
+    }
+
+    struct FailingSink(Arc<AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl AsyncSink<i32> for FailingSink {
+        async fn save(&self, item: &i32) -> Result<(), Box<dyn std::error::Error>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Err(format!("save failed for {item}").into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_surfaces_first_error_and_cancels_remaining_items() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let source = VecSource {
+            items: vec![1, 2, 3],
+        };
+
+        let pipeline = AsyncPipelineBuilder::new()
+            .add_source(Box::new(source))
+            .add_sink(Box::new(FailingSink(calls.clone())))
+            .build()
+            .unwrap();
+
+        let result = pipeline.run(1).await;
+
+        assert!(result.is_err());
+        // With a concurrency cap of 1, the second item's save is never
+        // even started once the first one fails.
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,273 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::error::Error;
+
+/// Wraps a `Sink<T>` so items are buffered and flushed to the inner
+/// sink's `save_batch` in one call instead of one `save` per item, once
+/// `cap` items have accumulated.
+pub struct BatchedSink<T> {
+    inner: Box<dyn crate::sink::Sink<T>>,
+    buffer: RefCell<VecDeque<T>>,
+    cap: usize,
+}
+
+impl<T: Clone> BatchedSink<T> {
+    pub fn new(inner: Box<dyn crate::sink::Sink<T>>, cap: usize) -> Self {
+        Self {
+            inner,
+            buffer: RefCell::new(VecDeque::with_capacity(cap)),
+            cap,
+        }
+    }
+
+    /// Sends any buffered items to the inner sink and clears the buffer.
+    pub fn flush(&self) -> Result<(), Box<dyn Error>> {
+        let items: Vec<T> = self.buffer.borrow_mut().drain(..).collect();
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        self.inner.save_batch(&items)
+    }
+}
+
+impl<T: Clone> crate::sink::Sink<T> for BatchedSink<T> {
+    fn save(&self, item: &T) -> Result<(), Box<dyn Error>> {
+        self.buffer.borrow_mut().push_back(item.clone());
+
+        if self.buffer.borrow().len() >= self.cap {
+            return self.flush();
+        }
+
+        Ok(())
+    }
+
+    fn flush(&self) -> Result<(), Box<dyn Error>> {
+        BatchedSink::flush(self)
+    }
+}
+
+/// The async flavour of `BatchedSink<T>`.
+#[cfg(feature = "async")]
+pub struct AsyncBatchedSink<T> {
+    inner: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+    buffer: std::sync::Mutex<VecDeque<T>>,
+    cap: usize,
+}
+
+#[cfg(feature = "async")]
+impl<T: Clone + Send + Sync> AsyncBatchedSink<T> {
+    pub fn new(inner: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>, cap: usize) -> Self {
+        Self {
+            inner,
+            buffer: std::sync::Mutex::new(VecDeque::with_capacity(cap)),
+            cap,
+        }
+    }
+
+    /// Sends any buffered items to the inner sink and clears the buffer.
+    pub async fn flush(&self) -> Result<(), Box<dyn Error>> {
+        let items: Vec<T> = self.buffer.lock().unwrap().drain(..).collect();
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        self.inner.save_batch(&items).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T: Clone + Send + Sync> crate::sink::AsyncSink<T> for AsyncBatchedSink<T> {
+    async fn save(&self, item: &T) -> Result<(), Box<dyn Error>> {
+        let should_flush = {
+            let mut buffer = self.buffer.lock().unwrap();
+            buffer.push_back(item.clone());
+            buffer.len() >= self.cap
+        };
+
+        if should_flush {
+            return self.flush().await;
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<(), Box<dyn Error>> {
+        AsyncBatchedSink::flush(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::Sink;
+    use std::sync::Arc;
+
+    struct RecordingSink {
+        batches: RefCell<Vec<Vec<i32>>>,
+    }
+
+    impl RecordingSink {
+        fn new() -> Self {
+            Self {
+                batches: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Sink<i32> for Arc<RecordingSink> {
+        fn save(&self, item: &i32) -> Result<(), Box<dyn Error>> {
+            self.batches.borrow_mut().push(vec![*item]);
+            Ok(())
+        }
+
+        fn save_batch(&self, items: &[i32]) -> Result<(), Box<dyn Error>> {
+            self.batches.borrow_mut().push(items.to_vec());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_buffer_flushes_once_it_reaches_cap() {
+        let inner = Arc::new(RecordingSink::new());
+        let batched = BatchedSink::new(Box::new(inner.clone()), 2);
+
+        batched.save(&1).unwrap();
+        assert!(inner.batches.borrow().is_empty());
+
+        batched.save(&2).unwrap();
+        assert_eq!(*inner.batches.borrow(), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_flush_drains_a_partial_buffer() {
+        let inner = Arc::new(RecordingSink::new());
+        let batched = BatchedSink::new(Box::new(inner.clone()), 10);
+
+        batched.save(&1).unwrap();
+        batched.save(&2).unwrap();
+        assert!(inner.batches.borrow().is_empty());
+
+        batched.flush().unwrap();
+        assert_eq!(*inner.batches.borrow(), vec![vec![1, 2]]);
+
+        // A second flush with nothing buffered is a no-op.
+        batched.flush().unwrap();
+        assert_eq!(*inner.batches.borrow(), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_cap_zero_flushes_on_every_save() {
+        let inner = Arc::new(RecordingSink::new());
+        let batched = BatchedSink::new(Box::new(inner.clone()), 0);
+
+        batched.save(&1).unwrap();
+        batched.save(&2).unwrap();
+
+        assert_eq!(*inner.batches.borrow(), vec![vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn test_pipeline_run_flushes_batched_sink_after_source_drains() {
+        let inner = Arc::new(RecordingSink::new());
+        let batched: Box<dyn Sink<i32>> = Box::new(BatchedSink::new(Box::new(inner.clone()), 10));
+
+        let pipeline = crate::pipeline::PipelineBuilder::new()
+            .add_source(Box::new(vec![1, 2, 3]))
+            .add_sink(batched)
+            .build()
+            .unwrap();
+
+        pipeline.run().unwrap();
+
+        // Never reached `cap`, so the only batch is the one `run()`
+        // triggers by flushing sinks after the source drains.
+        assert_eq!(*inner.batches.borrow(), vec![vec![3, 2, 1]]);
+    }
+
+    #[cfg(feature = "async")]
+    mod async_tests {
+        use super::*;
+        use crate::sink::AsyncSink;
+        use std::sync::Mutex;
+
+        struct AsyncRecordingSink {
+            batches: Mutex<Vec<Vec<i32>>>,
+        }
+
+        impl AsyncRecordingSink {
+            fn new() -> Self {
+                Self {
+                    batches: Mutex::new(vec![]),
+                }
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl AsyncSink<i32> for Arc<AsyncRecordingSink> {
+            async fn save(&self, item: &i32) -> Result<(), Box<dyn Error>> {
+                self.batches.lock().unwrap().push(vec![*item]);
+                Ok(())
+            }
+
+            async fn save_batch(&self, items: &[i32]) -> Result<(), Box<dyn Error>> {
+                self.batches.lock().unwrap().push(items.to_vec());
+                Ok(())
+            }
+        }
+
+        #[tokio::test]
+        async fn test_async_buffer_flushes_once_it_reaches_cap() {
+            let inner = Arc::new(AsyncRecordingSink::new());
+            let batched = AsyncBatchedSink::new(Box::new(inner.clone()), 2);
+
+            batched.save(&1).await.unwrap();
+            assert!(inner.batches.lock().unwrap().is_empty());
+
+            batched.save(&2).await.unwrap();
+            assert_eq!(*inner.batches.lock().unwrap(), vec![vec![1, 2]]);
+        }
+
+        #[tokio::test]
+        async fn test_async_flush_drains_a_partial_buffer() {
+            let inner = Arc::new(AsyncRecordingSink::new());
+            let batched = AsyncBatchedSink::new(Box::new(inner.clone()), 10);
+
+            batched.save(&1).await.unwrap();
+            batched.flush().await.unwrap();
+
+            assert_eq!(*inner.batches.lock().unwrap(), vec![vec![1]]);
+        }
+
+        struct VecSource {
+            items: Vec<i32>,
+        }
+
+        #[async_trait::async_trait]
+        impl crate::source::AsyncSource<i32> for VecSource {
+            async fn next(&mut self) -> Option<i32> {
+                self.items.pop()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_async_pipeline_run_flushes_batched_sink_after_source_drains() {
+            let inner = Arc::new(AsyncRecordingSink::new());
+            let batched: Box<dyn AsyncSink<i32> + Send + Sync> =
+                Box::new(AsyncBatchedSink::new(Box::new(inner.clone()), 10));
+
+            let pipeline = crate::async_pipeline::AsyncPipelineBuilder::new()
+                .add_source(Box::new(VecSource {
+                    items: vec![1, 2, 3],
+                }))
+                .add_sink(batched)
+                .build()
+                .unwrap();
+
+            pipeline.run(4).await.unwrap();
+
+            assert_eq!(*inner.batches.lock().unwrap(), vec![vec![3, 2, 1]]);
+        }
+    }
+}
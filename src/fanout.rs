@@ -0,0 +1,308 @@
+use std::error::Error;
+
+/// How a `Fanout` reports failures from its two branches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Return the first error encountered, even though both branches
+    /// were attempted.
+    FailFast,
+    /// Attempt both branches and return a combined error naming every
+    /// branch that failed.
+    Collect,
+}
+
+/// The inner errors are captured as their `Display` output rather than
+/// `Box<dyn Error>` so that `FanoutError` stays `Send + Sync`: holding
+/// the original `Box<dyn Error>` (which is not `Send`) across the second
+/// branch's `.await` in `AsyncFanout::save` would make that future
+/// ineligible for the `Send` bound `AsyncSink::save` requires.
+#[derive(thiserror::Error, Debug)]
+pub enum FanoutError {
+    #[error("left sink failed: {0}")]
+    Left(String),
+    #[error("right sink failed: {0}")]
+    Right(String),
+    #[error("both sinks failed (left: {left}, right: {right})")]
+    Both { left: String, right: String },
+}
+
+/// Clones each item to two sinks. Unlike a bare `Pipeline::run`, which
+/// aborts on the first sink error and so can leave the item unsaved in
+/// every other sink, `Fanout` always forwards the item to both branches
+/// and only then applies its `ErrorPolicy` to decide what to report.
+pub struct Fanout<T> {
+    left: Box<dyn crate::sink::Sink<T>>,
+    right: Box<dyn crate::sink::Sink<T>>,
+    policy: ErrorPolicy,
+}
+
+impl<T> Fanout<T> {
+    pub fn new(
+        left: Box<dyn crate::sink::Sink<T>>,
+        right: Box<dyn crate::sink::Sink<T>>,
+        policy: ErrorPolicy,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            policy,
+        }
+    }
+
+    pub fn into_inner(self) -> (Box<dyn crate::sink::Sink<T>>, Box<dyn crate::sink::Sink<T>>) {
+        (self.left, self.right)
+    }
+}
+
+impl<T> crate::sink::Sink<T> for Fanout<T> {
+    fn save(&self, item: &T) -> Result<(), Box<dyn Error>> {
+        let left_result = self.left.save(item);
+        let right_result = self.right.save(item);
+
+        match self.policy {
+            ErrorPolicy::FailFast => {
+                left_result?;
+                right_result?;
+                Ok(())
+            }
+            ErrorPolicy::Collect => match (left_result, right_result) {
+                (Ok(()), Ok(())) => Ok(()),
+                (Err(left), Ok(())) => {
+                    Err(Box::new(FanoutError::Left(left.to_string())) as Box<dyn Error>)
+                }
+                (Ok(()), Err(right)) => {
+                    Err(Box::new(FanoutError::Right(right.to_string())) as Box<dyn Error>)
+                }
+                (Err(left), Err(right)) => Err(Box::new(FanoutError::Both {
+                    left: left.to_string(),
+                    right: right.to_string(),
+                }) as Box<dyn Error>),
+            },
+        }
+    }
+}
+
+/// The async flavour of `Fanout<T>`.
+#[cfg(feature = "async")]
+pub struct AsyncFanout<T> {
+    left: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+    right: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+    policy: ErrorPolicy,
+}
+
+#[cfg(feature = "async")]
+impl<T> AsyncFanout<T> {
+    pub fn new(
+        left: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+        right: Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+        policy: ErrorPolicy,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            policy,
+        }
+    }
+
+    pub fn into_inner(
+        self,
+    ) -> (
+        Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+        Box<dyn crate::sink::AsyncSink<T> + Send + Sync>,
+    ) {
+        (self.left, self.right)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T: Sync> crate::sink::AsyncSink<T> for AsyncFanout<T> {
+    async fn save(&self, item: &T) -> Result<(), Box<dyn Error>> {
+        // Each result is stringified immediately after its `.await`, so no
+        // `Box<dyn Error>` (which is not `Send`) is ever held alive across
+        // the other branch's `.await` — see the note on `FanoutError`.
+        let left_result = self.left.save(item).await.map_err(|e| e.to_string());
+        let right_result = self.right.save(item).await.map_err(|e| e.to_string());
+
+        match self.policy {
+            ErrorPolicy::FailFast => {
+                left_result.map_err(FanoutError::Left)?;
+                right_result.map_err(FanoutError::Right)?;
+                Ok(())
+            }
+            ErrorPolicy::Collect => match (left_result, right_result) {
+                (Ok(()), Ok(())) => Ok(()),
+                (Err(left), Ok(())) => Err(Box::new(FanoutError::Left(left)) as Box<dyn Error>),
+                (Ok(()), Err(right)) => Err(Box::new(FanoutError::Right(right)) as Box<dyn Error>),
+                (Err(left), Err(right)) => {
+                    Err(Box::new(FanoutError::Both { left, right }) as Box<dyn Error>)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::Sink;
+    use std::sync::Arc;
+
+    struct RecordingSink {
+        received: std::cell::RefCell<Vec<i32>>,
+        fail: bool,
+    }
+
+    impl RecordingSink {
+        fn new(fail: bool) -> Self {
+            Self {
+                received: std::cell::RefCell::new(vec![]),
+                fail,
+            }
+        }
+    }
+
+    impl Sink<i32> for Arc<RecordingSink> {
+        fn save(&self, item: &i32) -> Result<(), Box<dyn Error>> {
+            self.received.borrow_mut().push(*item);
+            if self.fail {
+                return Err(format!("sink failed for {item}").into());
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_item_reaches_both_branches() {
+        let left = Arc::new(RecordingSink::new(false));
+        let right = Arc::new(RecordingSink::new(false));
+        let fanout = Fanout::new(
+            Box::new(left.clone()),
+            Box::new(right.clone()),
+            ErrorPolicy::FailFast,
+        );
+
+        fanout.save(&42).unwrap();
+
+        assert_eq!(*left.received.borrow(), vec![42]);
+        assert_eq!(*right.received.borrow(), vec![42]);
+    }
+
+    #[test]
+    fn test_fail_fast_returns_left_error_even_though_right_ran() {
+        let left = Arc::new(RecordingSink::new(true));
+        let right = Arc::new(RecordingSink::new(false));
+        let fanout = Fanout::new(
+            Box::new(left.clone()),
+            Box::new(right.clone()),
+            ErrorPolicy::FailFast,
+        );
+
+        let result = fanout.save(&1);
+
+        assert!(result.is_err());
+        assert_eq!(*right.received.borrow(), vec![1]);
+    }
+
+    #[test]
+    fn test_collect_reports_both_when_both_sinks_fail() {
+        let left = Arc::new(RecordingSink::new(true));
+        let right = Arc::new(RecordingSink::new(true));
+        let fanout = Fanout::new(Box::new(left), Box::new(right), ErrorPolicy::Collect);
+
+        let err = fanout.save(&1).unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<FanoutError>(),
+            Some(FanoutError::Both { .. })
+        ));
+    }
+
+    #[test]
+    fn test_collect_reports_ok_when_neither_sink_fails() {
+        let left = Arc::new(RecordingSink::new(false));
+        let right = Arc::new(RecordingSink::new(false));
+        let fanout = Fanout::new(Box::new(left), Box::new(right), ErrorPolicy::Collect);
+
+        assert!(fanout.save(&1).is_ok());
+    }
+
+    #[test]
+    fn test_into_inner_returns_both_sinks() {
+        let left = Arc::new(RecordingSink::new(false));
+        let right = Arc::new(RecordingSink::new(false));
+        let fanout = Fanout::new(
+            Box::new(left.clone()),
+            Box::new(right.clone()),
+            ErrorPolicy::FailFast,
+        );
+
+        let (inner_left, inner_right) = fanout.into_inner();
+
+        inner_left.save(&1).unwrap();
+        inner_right.save(&2).unwrap();
+        assert_eq!(*left.received.borrow(), vec![1]);
+        assert_eq!(*right.received.borrow(), vec![2]);
+    }
+
+    #[cfg(feature = "async")]
+    mod async_tests {
+        use super::*;
+        use crate::sink::AsyncSink;
+
+        struct AsyncRecordingSink {
+            received: std::sync::Mutex<Vec<i32>>,
+            fail: bool,
+        }
+
+        impl AsyncRecordingSink {
+            fn new(fail: bool) -> Self {
+                Self {
+                    received: std::sync::Mutex::new(vec![]),
+                    fail,
+                }
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl AsyncSink<i32> for Arc<AsyncRecordingSink> {
+            async fn save(&self, item: &i32) -> Result<(), Box<dyn Error>> {
+                self.received.lock().unwrap().push(*item);
+                if self.fail {
+                    return Err(format!("sink failed for {item}").into());
+                }
+                Ok(())
+            }
+        }
+
+        #[tokio::test]
+        async fn test_async_item_reaches_both_branches() {
+            let left = Arc::new(AsyncRecordingSink::new(false));
+            let right = Arc::new(AsyncRecordingSink::new(false));
+            let fanout = AsyncFanout::new(
+                Box::new(left.clone()),
+                Box::new(right.clone()),
+                ErrorPolicy::FailFast,
+            );
+
+            fanout.save(&42).await.unwrap();
+
+            assert_eq!(*left.received.lock().unwrap(), vec![42]);
+            assert_eq!(*right.received.lock().unwrap(), vec![42]);
+        }
+
+        #[tokio::test]
+        async fn test_async_collect_reports_both_when_both_sinks_fail() {
+            let left = Arc::new(AsyncRecordingSink::new(true));
+            let right = Arc::new(AsyncRecordingSink::new(true));
+            let fanout = AsyncFanout::new(Box::new(left), Box::new(right), ErrorPolicy::Collect);
+
+            let err = fanout.save(&1).await.unwrap_err();
+
+            assert!(matches!(
+                err.downcast_ref::<FanoutError>(),
+                Some(FanoutError::Both { .. })
+            ));
+        }
+    }
+}
@@ -6,9 +6,119 @@ pub trait Filter<T> {
     fn filter(&self, item: &T) -> bool;
 }
 
+/// Let any `Fn(&T) -> bool` closure be passed directly to `add_filter`
+/// without wrapping it in a bespoke struct.
+impl<T, F: Fn(&T) -> bool> Filter<T> for F {
+    fn filter(&self, item: &T) -> bool {
+        self(item)
+    }
+}
+
+/// Keeps the item only if every inner filter keeps it.
+pub struct And<T> {
+    filters: Vec<Box<dyn Filter<T>>>,
+}
+
+impl<T> Filter<T> for And<T> {
+    fn filter(&self, item: &T) -> bool {
+        self.filters.iter().all(|f| f.filter(item))
+    }
+}
+
+/// Keeps the item if at least one inner filter keeps it.
+pub struct Or<T> {
+    filters: Vec<Box<dyn Filter<T>>>,
+}
+
+impl<T> Filter<T> for Or<T> {
+    fn filter(&self, item: &T) -> bool {
+        self.filters.iter().any(|f| f.filter(item))
+    }
+}
+
+/// Inverts the result of the inner filter.
+pub struct Not<T> {
+    inner: Box<dyn Filter<T>>,
+}
+
+impl<T> Filter<T> for Not<T> {
+    fn filter(&self, item: &T) -> bool {
+        !self.inner.filter(item)
+    }
+}
+
+impl<T> dyn Filter<T> {
+    /// Keeps the item only if every filter in `filters` keeps it.
+    pub fn and(filters: Vec<Box<dyn Filter<T>>>) -> Box<dyn Filter<T>>
+    where
+        T: 'static,
+    {
+        Box::new(And { filters })
+    }
+
+    /// Keeps the item if at least one filter in `filters` keeps it.
+    pub fn or(filters: Vec<Box<dyn Filter<T>>>) -> Box<dyn Filter<T>>
+    where
+        T: 'static,
+    {
+        Box::new(Or { filters })
+    }
+
+    /// Keeps the item only if `inner` would have dropped it.
+    // Named to match `Filter::and`/`Filter::or`, not `std::ops::Not::not`
+    // (this takes a filter, not `self`, and returns a boxed filter).
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(inner: Box<dyn Filter<T>>) -> Box<dyn Filter<T>>
+    where
+        T: 'static,
+    {
+        Box::new(Not { inner })
+    }
+}
+
 /// The async flavour of `Filter<T>`
 #[cfg(feature = "async")]
 #[async_trait::async_trait]
 pub trait AsyncFilter<T> {
     async fn filter(&self, item: &T) -> bool;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_of_empty_filters_is_vacuously_true() {
+        let and: Box<dyn Filter<i32>> = <dyn Filter<i32>>::and(vec![]);
+
+        assert!(and.filter(&1));
+    }
+
+    #[test]
+    fn test_or_of_empty_filters_is_vacuously_false() {
+        let or: Box<dyn Filter<i32>> = <dyn Filter<i32>>::or(vec![]);
+
+        assert!(!or.filter(&1));
+    }
+
+    #[test]
+    fn test_not_and_composes_with_nested_filters() {
+        let is_even: Box<dyn Filter<i32>> = Box::new(|item: &i32| item % 2 == 0);
+        let is_positive: Box<dyn Filter<i32>> = Box::new(|item: &i32| *item > 0);
+
+        // not(even and positive): keeps odd numbers and non-positive numbers.
+        let filter = <dyn Filter<i32>>::not(<dyn Filter<i32>>::and(vec![is_even, is_positive]));
+
+        assert!(filter.filter(&3)); // odd, positive -> and() is false -> not() keeps it
+        assert!(filter.filter(&-2)); // even, non-positive -> and() is false -> not() keeps it
+        assert!(!filter.filter(&4)); // even, positive -> and() is true -> not() drops it
+    }
+
+    #[test]
+    fn test_blanket_fn_impl_is_usable_as_a_filter() {
+        let is_even = |item: &i32| item % 2 == 0;
+
+        assert!(is_even.filter(&2));
+        assert!(!is_even.filter(&3));
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(feature = "async")]
+pub mod async_pipeline;
+pub mod batched_sink;
+pub mod fanout;
+pub mod filter;
+pub mod pipeline;
+pub mod sink;
+pub mod source;
+pub mod transform;
+pub mod try_pipeline;
@@ -44,6 +44,51 @@ impl<T> PipelineBuilder<T> {
         self
     }
 
+    /// Consumes this builder and returns a new one over `Out`, applying
+    /// `transform` to every item that survives the filters already
+    /// registered on `self`. The source and filters collected so far are
+    /// carried forward by wrapping them behind the returned builder's
+    /// source, so `add_filter`/`add_sink` on the result operate on `Out`.
+    ///
+    /// Must be called before any `add_sink`: sinks are typed for `T`, so
+    /// there's no way to carry them forward once the pipeline becomes a
+    /// `PipelineBuilder<Out>`. Panics if any were already registered,
+    /// rather than silently dropping them.
+    ///
+    /// Unlike `build()`, these are programmer-error misuses of the
+    /// builder API rather than a runtime condition a caller would want
+    /// to handle (e.g. a missing source depending on user input) — so
+    /// they panic immediately at the call site instead of surfacing
+    /// through `BuilderError`.
+    pub fn map<Out: 'static>(
+        self,
+        transform: Box<dyn crate::transform::Transform<T, Out>>,
+    ) -> PipelineBuilder<Out>
+    where
+        T: 'static,
+    {
+        assert!(
+            self.sinks.is_empty(),
+            "map() must be called before add_sink(); sinks already registered for T would be silently dropped"
+        );
+
+        let source = self
+            .source
+            .expect("map() requires add_source() to be called first");
+
+        let wrapped = crate::transform::TransformedSource {
+            inner: source,
+            filters: self.filters,
+            transform,
+        };
+
+        PipelineBuilder {
+            source: Some(Box::new(wrapped)),
+            sinks: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
     pub fn build(self) -> Result<Pipeline<T>, BuilderError> {
         let source = match self.source {
             Some(source) => source,
@@ -70,6 +115,10 @@ impl<T> Pipeline<T> {
             }
         }
 
+        for sink in &self.sinks {
+            sink.flush()?;
+        }
+
         Ok(())
     }
 }
@@ -3,6 +3,22 @@ use std::error::Error;
 /// filters.
 pub trait Sink<T> {
     fn save(&self, item: &T) -> Result<(), Box<dyn Error>>;
+
+    /// Saves several items at once. The default implementation just
+    /// calls `save` for each item in order; sinks that can amortize
+    /// I/O across a batch (e.g. a single DB insert) should override it.
+    fn save_batch(&self, items: &[T]) -> Result<(), Box<dyn Error>> {
+        for item in items {
+            self.save(item)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes any items buffered by this sink. Sinks that don't buffer
+    /// have nothing to do here.
+    fn flush(&self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
 }
 
 /// The async flavour of `Sink<T>`
@@ -10,4 +26,20 @@ pub trait Sink<T> {
 #[async_trait::async_trait]
 pub trait AsyncSink<T> {
     async fn save(&self, item: &T) -> Result<(), Box<dyn Error>>;
+
+    /// The async flavour of `Sink::save_batch`.
+    async fn save_batch(&self, items: &[T]) -> Result<(), Box<dyn Error>>
+    where
+        T: Sync,
+    {
+        for item in items {
+            self.save(item).await?;
+        }
+        Ok(())
+    }
+
+    /// The async flavour of `Sink::flush`.
+    async fn flush(&self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
 }
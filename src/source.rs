@@ -3,6 +3,27 @@
 /// steps of the pipeline.
 pub trait Source<T> {
     fn next(&mut self) -> Option<T>;
+
+    /// Yields at most `n` items, then behaves as exhausted.
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take {
+            inner: self,
+            remaining: n,
+        }
+    }
+
+    /// Yields every `k`-th item, discarding the `k - 1` items in between.
+    fn step_by(self, k: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        assert!(k > 0, "step_by: k must be greater than zero");
+
+        StepBy { inner: self, step: k }
+    }
 }
 
 impl<T> Iterator for dyn Source<T> {
@@ -19,9 +40,193 @@ impl<T> Source<T> for Vec<T> {
     }
 }
 
+/// Wraps a `Source<T>` to yield at most a fixed number of items. See
+/// `Source::take`.
+pub struct Take<S> {
+    inner: S,
+    remaining: usize,
+}
+
+impl<T, S: Source<T>> Source<T> for Take<S> {
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.inner.next()
+    }
+}
+
+/// Wraps a `Source<T>` to yield every `k`-th item. See `Source::step_by`.
+pub struct StepBy<S> {
+    inner: S,
+    step: usize,
+}
+
+impl<T, S: Source<T>> Source<T> for StepBy<S> {
+    fn next(&mut self) -> Option<T> {
+        let item = self.inner.next()?;
+
+        for _ in 1..self.step {
+            if self.inner.next().is_none() {
+                break;
+            }
+        }
+
+        Some(item)
+    }
+}
+
 /// Async flavour of Source
 #[cfg(feature = "async")]
 #[async_trait::async_trait]
 pub trait AsyncSource<T> {
     async fn next(&mut self) -> Option<T>;
+
+    /// Spaces consecutive yields at least `d` apart.
+    fn throttle(self, d: std::time::Duration) -> Throttle<Self>
+    where
+        Self: Sized,
+    {
+        Throttle {
+            inner: self,
+            delay: d,
+            last_yield: None,
+        }
+    }
+}
+
+/// Wraps an `AsyncSource<T>` to space consecutive yields at least `delay`
+/// apart. See `AsyncSource::throttle`.
+///
+/// Uses `tokio::time::Instant` rather than `std::time::Instant` so the
+/// delay is measured against tokio's (pausable/advanceable) clock —
+/// this is what lets tests exercise the spacing with
+/// `tokio::time::pause`/`advance` instead of real sleeps.
+#[cfg(feature = "async")]
+pub struct Throttle<S> {
+    inner: S,
+    delay: std::time::Duration,
+    last_yield: Option<tokio::time::Instant>,
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T, S: AsyncSource<T> + Send> AsyncSource<T> for Throttle<S> {
+    async fn next(&mut self) -> Option<T> {
+        if let Some(last_yield) = self.last_yield {
+            let elapsed = last_yield.elapsed();
+            if elapsed < self.delay {
+                tokio::time::sleep(self.delay - elapsed).await;
+            }
+        }
+
+        let item = self.inner.next().await;
+        if item.is_some() {
+            self.last_yield = Some(tokio::time::Instant::now());
+        }
+
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingSource {
+        items: Vec<i32>,
+        calls: Cell<usize>,
+    }
+
+    impl Source<i32> for CountingSource {
+        fn next(&mut self) -> Option<i32> {
+            self.calls.set(self.calls.get() + 1);
+            self.items.pop()
+        }
+    }
+
+    #[test]
+    fn test_take_zero_does_not_touch_inner_source() {
+        let inner = CountingSource {
+            items: vec![1, 2, 3],
+            calls: Cell::new(0),
+        };
+        let mut taken = inner.take(0);
+
+        assert_eq!(taken.next(), None);
+        assert_eq!(taken.inner.calls.get(), 0);
+    }
+
+    #[test]
+    fn test_take_yields_at_most_n_items() {
+        let inner = vec![1, 2, 3, 4, 5];
+        let mut taken = inner.take(2);
+
+        assert_eq!(taken.next(), Some(5));
+        assert_eq!(taken.next(), Some(4));
+        assert_eq!(taken.next(), None);
+    }
+
+    #[test]
+    fn test_step_by_skips_k_minus_one_items() {
+        let inner = vec![5, 4, 3, 2, 1]; // Vec::next pops from the end
+        let mut stepped = inner.step_by(2);
+
+        assert_eq!(stepped.next(), Some(1));
+        assert_eq!(stepped.next(), Some(3));
+        assert_eq!(stepped.next(), Some(5));
+        assert_eq!(stepped.next(), None);
+    }
+
+    #[test]
+    fn test_step_by_boundary_when_inner_runs_out_mid_skip() {
+        let inner = vec![2, 1]; // Vec::next pops 1, then 2
+        let mut stepped = inner.step_by(3);
+
+        assert_eq!(stepped.next(), Some(1));
+        assert_eq!(stepped.next(), None);
+    }
+
+    struct AsyncVecSource {
+        items: Vec<i32>,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncSource<i32> for AsyncVecSource {
+        async fn next(&mut self) -> Option<i32> {
+            self.items.pop()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle_spaces_out_consecutive_yields() {
+        let inner = AsyncVecSource {
+            items: vec![3, 2, 1],
+        };
+        let mut throttled = inner.throttle(std::time::Duration::from_millis(100));
+
+        // The first yield doesn't wait: there's no prior yield to space from.
+        assert_eq!(throttled.next().await, Some(1));
+
+        // The second yield must wait out the remaining delay.
+        let start = tokio::time::Instant::now();
+        assert_eq!(throttled.next().await, Some(2));
+        assert!(tokio::time::Instant::now() - start >= std::time::Duration::from_millis(100));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle_does_not_wait_if_delay_already_elapsed() {
+        let inner = AsyncVecSource { items: vec![2, 1] };
+        let mut throttled = inner.throttle(std::time::Duration::from_millis(100));
+
+        assert_eq!(throttled.next().await, Some(1));
+        tokio::time::advance(std::time::Duration::from_millis(200)).await;
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(throttled.next().await, Some(2));
+        assert_eq!(tokio::time::Instant::now(), start);
+    }
 }
@@ -0,0 +1,181 @@
+/// Transform maps an item produced by the source (and kept by the
+/// filters) into a different type before it reaches the sinks.
+pub trait Transform<In, Out> {
+    fn transform(&self, item: In) -> Out;
+}
+
+/// The async flavour of `Transform<In, Out>`
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncTransform<In, Out> {
+    async fn transform(&self, item: In) -> Out;
+}
+
+/// Wraps a `Source<In>` and its registered filters, applying a
+/// `Transform<In, Out>` to every item that survives filtering.
+///
+/// This lets `PipelineBuilder<In>::map` hand back a
+/// `PipelineBuilder<Out>` without losing the source/filters already
+/// registered: they become part of the new source.
+pub(crate) struct TransformedSource<In, Out> {
+    pub(crate) inner: Box<dyn crate::source::Source<In>>,
+    pub(crate) filters: Vec<Box<dyn crate::filter::Filter<In>>>,
+    pub(crate) transform: Box<dyn Transform<In, Out>>,
+}
+
+impl<In, Out> crate::source::Source<Out> for TransformedSource<In, Out> {
+    fn next(&mut self) -> Option<Out> {
+        loop {
+            let item = self.inner.next()?;
+            if self.filters.iter().any(|f| !f.filter(&item)) {
+                continue;
+            }
+
+            return Some(self.transform.transform(item));
+        }
+    }
+}
+
+/// The async flavour of `TransformedSource`, backing
+/// `AsyncPipelineBuilder<In>::map`.
+#[cfg(feature = "async")]
+pub(crate) struct AsyncTransformedSource<In, Out> {
+    pub(crate) inner: Box<dyn crate::source::AsyncSource<In> + Send>,
+    pub(crate) filters: Vec<Box<dyn crate::filter::AsyncFilter<In> + Send + Sync>>,
+    pub(crate) transform: Box<dyn AsyncTransform<In, Out> + Send + Sync>,
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<In: Send, Out> crate::source::AsyncSource<Out> for AsyncTransformedSource<In, Out> {
+    async fn next(&mut self) -> Option<Out> {
+        loop {
+            let item = self.inner.next().await?;
+            let mut keep = true;
+            for filter in &self.filters {
+                if !filter.filter(&item).await {
+                    keep = false;
+                    break;
+                }
+            }
+            if !keep {
+                continue;
+            }
+
+            return Some(self.transform.transform(item).await);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::Filter;
+    use crate::source::Source;
+
+    struct IsEven;
+
+    impl Filter<i32> for IsEven {
+        fn filter(&self, item: &i32) -> bool {
+            item % 2 == 0
+        }
+    }
+
+    struct ToString;
+
+    impl Transform<i32, String> for ToString {
+        fn transform(&self, item: i32) -> String {
+            item.to_string()
+        }
+    }
+
+    #[test]
+    fn test_transformed_source_applies_filters_before_transform() {
+        let inner: Box<dyn crate::source::Source<i32>> = Box::new(vec![1, 2, 3, 4]);
+        let mut transformed = TransformedSource {
+            inner,
+            filters: vec![Box::new(IsEven)],
+            transform: Box::new(ToString),
+        };
+
+        // Vec::next pops from the end, so items arrive as 4, 3, 2, 1;
+        // only the even ones should survive, already transformed.
+        assert_eq!(transformed.next(), Some("4".to_string()));
+        assert_eq!(transformed.next(), Some("2".to_string()));
+        assert_eq!(transformed.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "map() must be called before add_sink()")]
+    fn test_map_after_add_sink_panics() {
+        struct NoopSink;
+        impl crate::sink::Sink<i32> for NoopSink {
+            fn save(&self, _: &i32) -> Result<(), Box<dyn std::error::Error>> {
+                Ok(())
+            }
+        }
+
+        crate::pipeline::PipelineBuilder::new()
+            .add_source(Box::new(vec![1, 2, 3]))
+            .add_sink(Box::new(NoopSink))
+            .map(Box::new(ToString));
+    }
+
+    #[test]
+    #[should_panic(expected = "map() requires add_source() to be called first")]
+    fn test_map_before_add_source_panics() {
+        crate::pipeline::PipelineBuilder::<i32>::new().map(Box::new(ToString));
+    }
+
+    #[cfg(feature = "async")]
+    mod async_tests {
+        use super::*;
+        use crate::filter::AsyncFilter;
+        use crate::source::AsyncSource;
+
+        struct AsyncVecSource {
+            items: Vec<i32>,
+        }
+
+        #[async_trait::async_trait]
+        impl AsyncSource<i32> for AsyncVecSource {
+            async fn next(&mut self) -> Option<i32> {
+                self.items.pop()
+            }
+        }
+
+        struct AsyncIsEven;
+
+        #[async_trait::async_trait]
+        impl AsyncFilter<i32> for AsyncIsEven {
+            async fn filter(&self, item: &i32) -> bool {
+                item % 2 == 0
+            }
+        }
+
+        struct AsyncToString;
+
+        #[async_trait::async_trait]
+        impl AsyncTransform<i32, String> for AsyncToString {
+            async fn transform(&self, item: i32) -> String {
+                item.to_string()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_async_transformed_source_applies_filters_before_transform() {
+            let inner: Box<dyn crate::source::AsyncSource<i32> + Send> = Box::new(AsyncVecSource {
+                items: vec![1, 2, 3, 4],
+            });
+            let mut transformed = AsyncTransformedSource {
+                inner,
+                filters: vec![Box::new(AsyncIsEven)],
+                transform: Box::new(AsyncToString),
+            };
+
+            assert_eq!(transformed.next().await, Some("4".to_string()));
+            assert_eq!(transformed.next().await, Some("2".to_string()));
+            assert_eq!(transformed.next().await, None);
+        }
+    }
+}
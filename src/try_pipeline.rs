@@ -0,0 +1,209 @@
+/// Pipeline variant for sources that produce `Result<T, E>` items.
+/// `Ok` values flow through the normal filter+sink chain; `Err` values
+/// are diverted to dedicated error sinks instead of being conflated
+/// with items the filters dropped, giving callers a dead-letter path.
+pub struct TryPipeline<T, E> {
+    source: Box<dyn crate::source::Source<Result<T, E>>>,
+    ok_filters: Vec<Box<dyn crate::filter::Filter<T>>>,
+    ok_sinks: Vec<Box<dyn crate::sink::Sink<T>>>,
+    err_sinks: Vec<Box<dyn crate::sink::Sink<E>>>,
+}
+
+pub struct TryPipelineBuilder<T, E> {
+    source: Option<Box<dyn crate::source::Source<Result<T, E>>>>,
+    ok_filters: Vec<Box<dyn crate::filter::Filter<T>>>,
+    ok_sinks: Vec<Box<dyn crate::sink::Sink<T>>>,
+    err_sinks: Vec<Box<dyn crate::sink::Sink<E>>>,
+}
+
+impl<T, E> TryPipelineBuilder<T, E> {
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            ok_filters: Vec::new(),
+            ok_sinks: Vec::new(),
+            err_sinks: Vec::new(),
+        }
+    }
+
+    pub fn add_source(mut self, source: Box<dyn crate::source::Source<Result<T, E>>>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn add_ok_filter(mut self, filter: Box<dyn crate::filter::Filter<T>>) -> Self {
+        self.ok_filters.push(filter);
+        self
+    }
+
+    pub fn add_sink(mut self, sink: Box<dyn crate::sink::Sink<T>>) -> Self {
+        self.ok_sinks.push(sink);
+        self
+    }
+
+    pub fn add_err_sink(mut self, sink: Box<dyn crate::sink::Sink<E>>) -> Self {
+        self.err_sinks.push(sink);
+        self
+    }
+
+    pub fn build(self) -> Result<TryPipeline<T, E>, crate::pipeline::BuilderError> {
+        let source = match self.source {
+            Some(source) => source,
+            None => return Err(crate::pipeline::BuilderError::NoSource),
+        };
+
+        Ok(TryPipeline {
+            source,
+            ok_filters: self.ok_filters,
+            ok_sinks: self.ok_sinks,
+            err_sinks: self.err_sinks,
+        })
+    }
+}
+
+impl<T, E> TryPipeline<T, E> {
+    pub fn run(self) -> Result<(), Box<dyn std::error::Error>> {
+        for item in self.source {
+            match item {
+                Ok(item) => {
+                    if self.ok_filters.iter().any(|f| !f.filter(&item)) {
+                        continue;
+                    }
+
+                    for sink in &self.ok_sinks {
+                        sink.save(&item)?;
+                    }
+                }
+                Err(err) => {
+                    for sink in &self.err_sinks {
+                        sink.save(&err)?;
+                    }
+                }
+            }
+        }
+
+        for sink in &self.ok_sinks {
+            sink.flush()?;
+        }
+        for sink in &self.err_sinks {
+            sink.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::Filter;
+    use crate::sink::Sink;
+    use std::sync::Arc;
+
+    struct IsEven;
+
+    impl Filter<i32> for IsEven {
+        fn filter(&self, item: &i32) -> bool {
+            item % 2 == 0
+        }
+    }
+
+    struct MockSink<T> {
+        saved: std::cell::RefCell<Vec<T>>,
+    }
+
+    impl<T: Clone> MockSink<T> {
+        fn new() -> Self {
+            Self {
+                saved: std::cell::RefCell::new(vec![]),
+            }
+        }
+
+        fn get_saved(&self) -> Vec<T> {
+            self.saved.borrow().clone()
+        }
+    }
+
+    impl<T: Clone> Sink<T> for Arc<MockSink<T>> {
+        fn save(&self, item: &T) -> Result<(), Box<dyn std::error::Error>> {
+            self.saved.borrow_mut().push(item.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ok_items_go_through_ok_filters_and_ok_sinks() {
+        let source: Vec<Result<i32, String>> =
+            vec![Ok(1), Ok(2), Ok(3), Ok(4)].into_iter().rev().collect();
+        let ok_sink = Arc::new(MockSink::new());
+        let err_sink = Arc::new(MockSink::new());
+
+        let pipeline = TryPipelineBuilder::new()
+            .add_source(Box::new(source))
+            .add_ok_filter(Box::new(IsEven))
+            .add_sink(Box::new(ok_sink.clone()))
+            .add_err_sink(Box::new(err_sink.clone()))
+            .build()
+            .unwrap();
+
+        pipeline.run().unwrap();
+
+        assert_eq!(ok_sink.get_saved(), vec![2, 4]);
+        assert!(err_sink.get_saved().is_empty());
+    }
+
+    #[test]
+    fn test_err_items_are_routed_to_err_sinks_not_ok_filters() {
+        let source: Vec<Result<i32, String>> = vec![Ok(1), Err("boom".to_string()), Ok(2)]
+            .into_iter()
+            .rev()
+            .collect();
+        let ok_sink = Arc::new(MockSink::new());
+        let err_sink = Arc::new(MockSink::new());
+
+        let pipeline = TryPipelineBuilder::new()
+            .add_source(Box::new(source))
+            .add_ok_filter(Box::new(IsEven)) // drops Ok(1), keeps Ok(2)
+            .add_sink(Box::new(ok_sink.clone()))
+            .add_err_sink(Box::new(err_sink.clone()))
+            .build()
+            .unwrap();
+
+        pipeline.run().unwrap();
+
+        assert_eq!(ok_sink.get_saved(), vec![2]);
+        assert_eq!(err_sink.get_saved(), vec!["boom".to_string()]);
+    }
+
+    #[test]
+    fn test_both_ok_sinks_and_err_sinks_are_flushed_after_source_drains() {
+        let source: Vec<Result<i32, String>> = vec![Ok(1), Err("boom".to_string())]
+            .into_iter()
+            .rev()
+            .collect();
+        let ok_sink: Arc<MockSink<i32>> = Arc::new(MockSink::new());
+        let ok_batched = Box::new(crate::batched_sink::BatchedSink::new(
+            Box::new(ok_sink.clone()),
+            10,
+        ));
+        let err_sink: Arc<MockSink<String>> = Arc::new(MockSink::new());
+        let err_batched = Box::new(crate::batched_sink::BatchedSink::new(
+            Box::new(err_sink.clone()),
+            10,
+        ));
+
+        let pipeline = TryPipelineBuilder::new()
+            .add_source(Box::new(source))
+            .add_sink(ok_batched)
+            .add_err_sink(err_batched)
+            .build()
+            .unwrap();
+
+        pipeline.run().unwrap();
+
+        // Neither batch reached `cap`, so these were only saved because
+        // `run()` flushed both sink vecs after the source drained.
+        assert_eq!(ok_sink.get_saved(), vec![1]);
+        assert_eq!(err_sink.get_saved(), vec!["boom".to_string()]);
+    }
+}